@@ -1,11 +1,151 @@
-use std::fs::File;
-use std::io::Write;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+
 use anyhow::Result;
 use crate::models::Product;
 
-pub fn save_to_file(product: &Product, filename: &str) -> Result<()> {
-    let json = serde_json::to_string_pretty(product)?;
-    let mut file = File::create(filename)?;
-    file.write_all(json.as_bytes())?;
+/// A change detected between two snapshots of the same product, taken at different times.
+#[derive(Debug, PartialEq)]
+pub enum ProductChange {
+    Title { old: String, new: String },
+    Price { old: Option<f64>, new: Option<f64> },
+    Availability { old: Option<String>, new: Option<String> },
+}
+
+/// Appends `product` as a new snapshot line to `filename`, in JSON Lines format.
+pub fn append_snapshot(product: &Product, filename: &str) -> Result<()> {
+    let json = serde_json::to_string(product)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(filename)?;
+    writeln!(file, "{}", json)?;
     Ok(())
 }
+
+/// Loads every snapshot for `product_id` from `filename`, in the order they were recorded.
+/// Returns an empty list if the file doesn't exist yet.
+pub fn load_snapshots(filename: &str, product_id: u64) -> Result<Vec<Product>> {
+    if !std::path::Path::new(filename).exists() {
+        return Ok(Vec::new());
+    }
+
+    let file = fs::File::open(filename)?;
+    let reader = BufReader::new(file);
+    let mut snapshots = Vec::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let product: Product = serde_json::from_str(&line)?;
+        if product.product_id == product_id {
+            snapshots.push(product);
+        }
+    }
+
+    Ok(snapshots)
+}
+
+/// Diffs two snapshots of the same product, returning the fields that changed between them.
+pub fn diff_snapshots(old: &Product, new: &Product) -> Vec<ProductChange> {
+    let mut changes = Vec::new();
+
+    if old.title != new.title {
+        changes.push(ProductChange::Title {
+            old: old.title.clone(),
+            new: new.title.clone(),
+        });
+    }
+
+    if old.price != new.price {
+        changes.push(ProductChange::Price {
+            old: old.price,
+            new: new.price,
+        });
+    }
+
+    if old.availability != new.availability {
+        changes.push(ProductChange::Availability {
+            old: old.availability.clone(),
+            new: new.availability.clone(),
+        });
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn product(title: &str, price: Option<f64>, availability: Option<&str>) -> Product {
+        Product {
+            product_id: 1,
+            title: title.to_string(),
+            price,
+            currency: None,
+            availability: availability.map(str::to_string),
+            seller: None,
+            rating: None,
+            images: Vec::new(),
+            timestamp: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_snapshots_reports_no_changes_when_identical() {
+        let a = product("Widget", Some(9.99), Some("In Stock"));
+        let b = product("Widget", Some(9.99), Some("In Stock"));
+        assert!(diff_snapshots(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn diff_snapshots_reports_title_price_and_availability_changes() {
+        let old = product("Widget", Some(9.99), Some("In Stock"));
+        let new = product("Widget Pro", Some(14.99), Some("Out of Stock"));
+
+        let changes = diff_snapshots(&old, &new);
+
+        assert_eq!(
+            changes,
+            vec![
+                ProductChange::Title {
+                    old: "Widget".to_string(),
+                    new: "Widget Pro".to_string(),
+                },
+                ProductChange::Price {
+                    old: Some(9.99),
+                    new: Some(14.99),
+                },
+                ProductChange::Availability {
+                    old: Some("In Stock".to_string()),
+                    new: Some("Out of Stock".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn append_and_load_round_trip_and_filter_by_product_id() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("archiver_test_{:?}.jsonl", std::thread::current().id()));
+        let path = path.to_str().unwrap();
+        let _ = fs::remove_file(path);
+
+        let mut other = product("Other", None, None);
+        other.product_id = 2;
+        append_snapshot(&other, path).unwrap();
+        append_snapshot(&product("Widget", Some(1.0), None), path).unwrap();
+        append_snapshot(&product("Widget v2", Some(2.0), None), path).unwrap();
+
+        let snapshots = load_snapshots(path, 1).unwrap();
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0].title, "Widget");
+        assert_eq!(snapshots[1].title, "Widget v2");
+
+        let _ = fs::remove_file(path);
+    }
+}
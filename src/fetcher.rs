@@ -1,13 +1,37 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
 use reqwest::blocking::get;
 use reqwest::redirect;
+use reqwest::StatusCode;
+
+use crate::http_cache::{parse_cache_control, CacheEntry, HttpCache, DEFAULT_CACHE_PATH};
 
 pub fn _fetch_html_old(url: &str) -> Result<String> {
     let resp = get(url)?;
     Ok(resp.text()?)
 }
 
-pub fn fetch_html(url: &str) -> Result<String, reqwest::Error> {
+/// Returns the cached body for `url` regardless of freshness, for `--offline` re-parsing.
+/// Errors if the page was never cached.
+pub fn fetch_html_offline(url: &str) -> Result<String> {
+    let cache = HttpCache::load(DEFAULT_CACHE_PATH)?;
+    cache
+        .get(url)
+        .map(|entry| entry.body.clone())
+        .ok_or_else(|| anyhow!("--offline was set but no cached response exists for {}", url))
+}
+
+pub fn fetch_html(url: &str, force_refresh: bool, app_ttl_secs: i64) -> Result<String> {
+    let mut cache = HttpCache::load(DEFAULT_CACHE_PATH)?;
+
+    if !force_refresh {
+        if let Some(entry) = cache.get(url) {
+            if entry.is_fresh() {
+                return Ok(entry.body.clone());
+            }
+        }
+    }
+
     let custom_redirect_policy = redirect::Policy::custom(|attempt| {
         if attempt.previous().len() > 100 {
             attempt.error("Too many redirects (>100)")
@@ -23,9 +47,74 @@ pub fn fetch_html(url: &str) -> Result<String, reqwest::Error> {
         .redirect(custom_redirect_policy)
         .build()?;
 
-    client.get(url)
-        .header("User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.3")
-        .send()?
-        .text()
-        .map_err(|e| e.into())
+    let mut request = client.get(url).header(
+        "User-Agent",
+        "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.3",
+    );
+
+    if let Some(entry) = cache.get(url) {
+        if let Some(etag) = &entry.etag {
+            request = request.header("If-None-Match", etag.clone());
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            request = request.header("If-Modified-Since", last_modified.clone());
+        }
+    }
+
+    let response = request.send()?;
+    let status = response.status();
+
+    if status == StatusCode::NOT_MODIFIED {
+        // The server confirmed the cached body is still current, so its freshness window
+        // restarts now even though the body itself didn't change.
+        if let Some(entry) = cache.get(url).cloned() {
+            let body = entry.body.clone();
+            cache.insert(
+                url.to_string(),
+                CacheEntry {
+                    cached_at: Utc::now(),
+                    ..entry
+                },
+            );
+            cache.save(DEFAULT_CACHE_PATH)?;
+            return Ok(body);
+        }
+        return Ok(String::new());
+    }
+
+    let etag = response
+        .headers()
+        .get("ETag")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let last_modified = response
+        .headers()
+        .get("Last-Modified")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let (no_store, max_age_secs) = response
+        .headers()
+        .get("Cache-Control")
+        .and_then(|v| v.to_str().ok())
+        .map(parse_cache_control)
+        .unwrap_or((false, None));
+
+    let body = response.text()?;
+
+    if status.is_success() && !no_store {
+        cache.insert(
+            url.to_string(),
+            CacheEntry {
+                body: body.clone(),
+                etag,
+                last_modified,
+                cached_at: Utc::now(),
+                max_age_secs,
+                app_ttl_secs,
+            },
+        );
+        cache.save(DEFAULT_CACHE_PATH)?;
+    }
+
+    Ok(body)
 }
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Default location of the persistent HTTP cache, relative to the working directory.
+pub const DEFAULT_CACHE_PATH: &str = "http_cache.json";
+
+/// Default app-level TTL used to decide freshness when the server sends no `Cache-Control`,
+/// so re-archiving the same product during development doesn't re-fetch on every run.
+pub const DEFAULT_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// A single cached response, along with the validators needed to make a conditional request.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct CacheEntry {
+    pub body: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub cached_at: DateTime<Utc>,
+    /// `Cache-Control: max-age` in seconds, if the server sent one.
+    pub max_age_secs: Option<i64>,
+    /// App-level TTL to fall back on when `max_age_secs` is `None`.
+    pub app_ttl_secs: i64,
+}
+
+impl CacheEntry {
+    /// Whether this entry is still fresh, per `Cache-Control: max-age` if the server sent one,
+    /// or `app_ttl_secs` otherwise — without needing to revalidate with the server at all.
+    pub fn is_fresh(&self) -> bool {
+        let ttl = self.max_age_secs.unwrap_or(self.app_ttl_secs);
+        Utc::now().signed_duration_since(self.cached_at).num_seconds() < ttl
+    }
+}
+
+/// A persistent, on-disk cache of HTTP responses keyed by request URL.
+#[derive(Default, Serialize, Deserialize)]
+pub struct HttpCache {
+    entries: HashMap<String, CacheEntry>,
+}
+
+impl HttpCache {
+    /// Loads the cache from `path`, or returns an empty cache if it doesn't exist yet.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn get(&self, url: &str) -> Option<&CacheEntry> {
+        self.entries.get(url)
+    }
+
+    pub fn insert(&mut self, url: String, entry: CacheEntry) {
+        self.entries.insert(url, entry);
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path: PathBuf = path.as_ref().to_path_buf();
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(path, contents)?;
+        Ok(())
+    }
+}
+
+/// Parses a `Cache-Control` header value, returning `(no_store, max_age_secs)`.
+pub fn parse_cache_control(value: &str) -> (bool, Option<i64>) {
+    let mut no_store = false;
+    let mut max_age = None;
+
+    for directive in value.split(',') {
+        let directive = directive.trim();
+        if directive.eq_ignore_ascii_case("no-store") {
+            no_store = true;
+        } else if let Some(seconds) = directive
+            .to_ascii_lowercase()
+            .strip_prefix("max-age=")
+            .and_then(|s| s.parse::<i64>().ok())
+        {
+            max_age = Some(seconds);
+        }
+    }
+
+    (no_store, max_age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_cache_control_reads_max_age() {
+        assert_eq!(parse_cache_control("max-age=120"), (false, Some(120)));
+    }
+
+    #[test]
+    fn parse_cache_control_reads_no_store() {
+        assert_eq!(parse_cache_control("no-store"), (true, None));
+    }
+
+    #[test]
+    fn parse_cache_control_reads_combined_directives() {
+        assert_eq!(
+            parse_cache_control("private, max-age=60, must-revalidate"),
+            (false, Some(60))
+        );
+    }
+
+    #[test]
+    fn parse_cache_control_defaults_when_absent() {
+        assert_eq!(parse_cache_control("private"), (false, None));
+    }
+
+    #[test]
+    fn entry_with_no_max_age_falls_back_to_app_ttl() {
+        let mut entry = CacheEntry {
+            body: String::new(),
+            etag: None,
+            last_modified: None,
+            cached_at: Utc::now(),
+            max_age_secs: None,
+            app_ttl_secs: 3600,
+        };
+        assert!(entry.is_fresh());
+
+        entry.app_ttl_secs = 0;
+        assert!(!entry.is_fresh());
+    }
+
+    #[test]
+    fn entry_within_max_age_is_fresh() {
+        let entry = CacheEntry {
+            body: String::new(),
+            etag: None,
+            last_modified: None,
+            cached_at: Utc::now(),
+            max_age_secs: Some(3600),
+            app_ttl_secs: 0,
+        };
+        assert!(entry.is_fresh());
+    }
+}
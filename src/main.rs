@@ -1,23 +1,49 @@
 mod fetcher;
+mod http_cache;
 mod parser;
 mod models;
 mod archiver;
 
 use anyhow::Result;
+use url::Url;
 
+use parser::{AliExpressParser, ParserRegistry};
+
+fn build_parser_registry() -> ParserRegistry {
+    let mut registry = ParserRegistry::new();
+    registry.register(Box::new(AliExpressParser));
+    registry
+}
 
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let offline = args.iter().any(|a| a == "--offline");
+    let force_refresh = args.iter().any(|a| a == "--force-refresh");
+
     let product_id: u64 = 1005007181903595;
     let url = get_product_url(product_id);
-    let html = fetcher::fetch_html(&url)?;
+    let parsed_url = Url::parse(&url)?;
+
+    let html = if offline {
+        fetcher::fetch_html_offline(&url)?
+    } else {
+        fetcher::fetch_html(&url, force_refresh, http_cache::DEFAULT_TTL_SECS)?
+    };
 
-    // Debugging: Write the HTML to a file
-    std::fs::write(format!("product_page_{}.html", product_id), &html)
-        .expect("Failed to write HTML to file");
+    let registry = build_parser_registry();
+    let product = registry.parse(product_id, &parsed_url, &html)?;
 
-    let product = parser::parse_product(product_id, &html)?;
+    let archive_path = "archive.jsonl";
+    let previous = archiver::load_snapshots(archive_path, product_id)?
+        .into_iter()
+        .last();
+    if let Some(previous) = previous {
+        for change in archiver::diff_snapshots(&previous, &product) {
+            println!("Change detected: {:?}", change);
+        }
+    }
 
-    archiver::save_to_file(&product, "archive.json")?;
+    archiver::append_snapshot(&product, archive_path)?;
     println!("Product archived successfully.");
     Ok(())
 }
@@ -1,8 +1,20 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Product {
     pub product_id: u64,
     pub title: String,
+    /// Numeric price, in `currency`'s units. `None` if it could not be located.
+    pub price: Option<f64>,
+    /// ISO-4217 currency code (e.g. "USD"), if known.
+    pub currency: Option<String>,
+    /// Raw availability/stock text as shown on the page (e.g. "In Stock", "Only 3 left").
+    pub availability: Option<String>,
+    /// Seller or store name.
+    pub seller: Option<String>,
+    /// Average rating, typically on a 0-5 scale.
+    pub rating: Option<f64>,
+    /// URLs of the product's images.
+    pub images: Vec<String>,
     pub timestamp: String,
 }
@@ -0,0 +1,212 @@
+use scraper::{Html, Selector};
+use anyhow::Result;
+use serde_json::Value;
+use url::Url;
+
+use crate::models::Product;
+use super::registry::SiteParser;
+
+/// CSS selectors can't reach AliExpress's page data since it's never rendered into DOM nodes —
+/// it's assigned to this JS variable inside a `<script>` tag, so it's pulled out with
+/// `serde_json` instead.
+const RUN_PARAMS_MARKER: &str = "window.runParams.data = ";
+
+/// Parses AliExpress product pages (including regional mirrors like `vi.aliexpress.com`).
+pub struct AliExpressParser;
+
+impl SiteParser for AliExpressParser {
+    fn can_parse(&self, url: &Url) -> bool {
+        url.host_str()
+            .map(|host| host == "aliexpress.com" || host.ends_with(".aliexpress.com"))
+            .unwrap_or(false)
+    }
+
+    fn parse(&self, product_id: u64, doc: &Html) -> Result<Product> {
+        let title_selector = Selector::parse("h1.product-title-text").unwrap();
+
+        let page_data = find_page_data(doc);
+
+        let title = doc
+            .select(&title_selector)
+            .next()
+            .map(|e| e.inner_html().trim().to_string())
+            .or_else(|| {
+                page_data
+                    .as_ref()
+                    .and_then(|data| data.pointer("/titleModule/subject"))
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| "Unknown Title".into());
+
+        let price = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/priceModule/minAmount/value"))
+            .and_then(Value::as_f64);
+
+        let currency = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/priceModule/minAmount/currency"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let availability = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/actionModule/stock"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let seller = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/storeModule/storeName"))
+            .and_then(Value::as_str)
+            .map(str::to_string);
+
+        let rating = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/titleModule/feedbackRating/averageStar"))
+            .and_then(Value::as_f64);
+
+        let images = page_data
+            .as_ref()
+            .and_then(|data| data.pointer("/imageModule/imagePathList"))
+            .and_then(Value::as_array)
+            .map(|list| {
+                list.iter()
+                    .filter_map(Value::as_str)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Product {
+            product_id,
+            title,
+            price,
+            currency,
+            availability,
+            seller,
+            rating,
+            images,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+fn find_page_data(doc: &Html) -> Option<Value> {
+    let script_selector = Selector::parse("script").unwrap();
+
+    doc.select(&script_selector).find_map(|script| {
+        let text = script.inner_html();
+        let start = text.find(RUN_PARAMS_MARKER)? + RUN_PARAMS_MARKER.len();
+        let json_slice = extract_json_object(&text[start..])?;
+        serde_json::from_str(json_slice).ok()
+    })
+}
+
+/// Given text starting at a `{`, returns the slice spanning the balanced JSON object, ignoring
+/// braces inside string literals.
+fn extract_json_object(text: &str) -> Option<&str> {
+    let mut depth = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&text[..=i]);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE_WITH_RUN_PARAMS: &str = r#"
+        <html>
+          <body>
+            <h1 class="product-title-text">Wireless Mouse</h1>
+            <script>
+              window.runParams.data = {
+                "titleModule": { "subject": "Wireless Mouse", "feedbackRating": { "averageStar": 4.7 } },
+                "priceModule": { "minAmount": { "value": 12.99, "currency": "USD" } },
+                "actionModule": { "stock": "In Stock" },
+                "storeModule": { "storeName": "Acme Gadgets" },
+                "imageModule": { "imagePathList": ["https://example.com/a.jpg", "https://example.com/b.jpg"] }
+              };
+            </script>
+          </body>
+        </html>
+    "#;
+
+    const FIXTURE_WITHOUT_RUN_PARAMS: &str = r#"
+        <html>
+          <body>
+            <h1 class="product-title-text">Bare Title Only</h1>
+          </body>
+        </html>
+    "#;
+
+    #[test]
+    fn can_parse_matches_aliexpress_hosts_only() {
+        let parser = AliExpressParser;
+        assert!(parser.can_parse(&Url::parse("https://aliexpress.com/item/1.html").unwrap()));
+        assert!(parser.can_parse(&Url::parse("https://vi.aliexpress.com/item/1.html").unwrap()));
+        assert!(!parser.can_parse(&Url::parse("https://notaliexpress.com/item/1.html").unwrap()));
+        assert!(!parser.can_parse(&Url::parse("https://evil-aliexpress.com/item/1.html").unwrap()));
+    }
+
+    #[test]
+    fn parse_extracts_fields_from_embedded_run_params() {
+        let parser = AliExpressParser;
+        let doc = Html::parse_document(FIXTURE_WITH_RUN_PARAMS);
+
+        let product = parser.parse(42, &doc).unwrap();
+
+        assert_eq!(product.product_id, 42);
+        assert_eq!(product.title, "Wireless Mouse");
+        assert_eq!(product.price, Some(12.99));
+        assert_eq!(product.currency, Some("USD".to_string()));
+        assert_eq!(product.availability, Some("In Stock".to_string()));
+        assert_eq!(product.seller, Some("Acme Gadgets".to_string()));
+        assert_eq!(product.rating, Some(4.7));
+        assert_eq!(
+            product.images,
+            vec!["https://example.com/a.jpg", "https://example.com/b.jpg"]
+        );
+    }
+
+    #[test]
+    fn parse_falls_back_to_defaults_without_run_params() {
+        let parser = AliExpressParser;
+        let doc = Html::parse_document(FIXTURE_WITHOUT_RUN_PARAMS);
+
+        let product = parser.parse(7, &doc).unwrap();
+
+        assert_eq!(product.title, "Bare Title Only");
+        assert_eq!(product.price, None);
+        assert_eq!(product.currency, None);
+        assert!(product.images.is_empty());
+    }
+}
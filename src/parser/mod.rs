@@ -0,0 +1,6 @@
+mod aliexpress;
+mod registry;
+
+pub use registry::ParserRegistry;
+
+pub use aliexpress::AliExpressParser;
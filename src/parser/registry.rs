@@ -0,0 +1,88 @@
+use anyhow::{anyhow, Result};
+use scraper::Html;
+use url::Url;
+
+use crate::models::Product;
+
+/// A parser capable of extracting a `Product` from a single site's product page.
+pub trait SiteParser {
+    /// Returns whether this parser knows how to handle pages served from `url`.
+    fn can_parse(&self, url: &Url) -> bool;
+
+    /// Parses a previously-fetched document into a `Product`.
+    fn parse(&self, product_id: u64, doc: &Html) -> Result<Product>;
+}
+
+/// Holds the set of known `SiteParser`s and dispatches to the first match for a given URL.
+#[derive(Default)]
+pub struct ParserRegistry {
+    parsers: Vec<Box<dyn SiteParser>>,
+}
+
+impl ParserRegistry {
+    pub fn new() -> Self {
+        Self { parsers: Vec::new() }
+    }
+
+    pub fn register(&mut self, parser: Box<dyn SiteParser>) {
+        self.parsers.push(parser);
+    }
+
+    /// Finds the first registered parser whose `can_parse` matches `url` and runs it against `html`.
+    pub fn parse(&self, product_id: u64, url: &Url, html: &str) -> Result<Product> {
+        let parser = self
+            .parsers
+            .iter()
+            .find(|p| p.can_parse(url))
+            .ok_or_else(|| anyhow!("no registered parser can handle URL: {}", url))?;
+
+        let doc = Html::parse_document(html);
+        parser.parse(product_id, &doc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct AlwaysParser;
+    impl SiteParser for AlwaysParser {
+        fn can_parse(&self, _url: &Url) -> bool {
+            true
+        }
+        fn parse(&self, product_id: u64, _doc: &Html) -> Result<Product> {
+            Ok(Product {
+                product_id,
+                title: "matched".to_string(),
+                price: None,
+                currency: None,
+                availability: None,
+                seller: None,
+                rating: None,
+                images: Vec::new(),
+                timestamp: "2026-01-01T00:00:00Z".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn parse_dispatches_to_first_matching_parser() {
+        let mut registry = ParserRegistry::new();
+        registry.register(Box::new(AlwaysParser));
+
+        let url = Url::parse("https://example.com/item/1.html").unwrap();
+        let product = registry.parse(1, &url, "<html></html>").unwrap();
+
+        assert_eq!(product.title, "matched");
+    }
+
+    #[test]
+    fn parse_errors_when_no_parser_matches() {
+        let registry = ParserRegistry::new();
+        let url = Url::parse("https://example.com/item/1.html").unwrap();
+
+        let err = registry.parse(1, &url, "<html></html>").unwrap_err();
+
+        assert!(err.to_string().contains("no registered parser"));
+    }
+}